@@ -18,21 +18,49 @@
 use std::any::Any;
 use std::sync::Arc;
 
-use arrow::array::{Array, Int64Array};
-use arrow::datatypes::{DataType, Field, FieldRef};
-use arrow::datatypes::DataType::{Int32, Int64};
-use datafusion_common::cast::as_int32_array;
+use arrow::array::{Array, Decimal128Array, Decimal256Array, Int64Array};
+use arrow::datatypes::DataType::{Int16, Int32, Int64, Int8};
+use arrow::datatypes::{DataType, Field, FieldRef, i256};
+use datafusion_common::cast::{as_int16_array, as_int32_array, as_int64_array, as_int8_array};
 use datafusion_common::{
     DataFusionError, Result, ScalarValue, exec_err, utils::take_function_args,
 };
 use datafusion_expr::Signature;
-use datafusion_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Volatility, ReturnFieldArgs};
+use datafusion_expr::{ColumnarValue, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Volatility};
+
+/// The precision of the `Decimal128` factorial result (scale 0), large enough
+/// to hold `33!` (≈8.7e36 < 10^38).
+const DECIMAL128_PRECISION: u8 = 38;
+
+/// The precision of the `Decimal256` factorial result (scale 0), large enough
+/// to hold `56!` (≈7.1e74 < 10^76); `57!` (≈4.05e76) has 77 digits and no
+/// longer fits.
+const DECIMAL256_PRECISION: u8 = 76;
+
+/// Controls the output type of [`SparkFactorial`].
+///
+/// Spark's `factorial` only ever returns `BIGINT` and yields NULL once the
+/// result overflows `i64` (i.e. for `n > 20`). This mode is an opt-in
+/// extension that widens the result to a `Decimal` type so that larger
+/// factorials remain representable, at the cost of the result no longer
+/// matching Spark's own output type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FactorialPrecision {
+    /// Spark-compatible behavior: `i64` lookup table, NULL for `n > 20`.
+    #[default]
+    Int64,
+    /// Widen the result to `Decimal128(38, 0)`, representable up to `33!`.
+    Decimal128,
+    /// Widen the result to `Decimal256(76, 0)`, representable up to `56!`.
+    Decimal256,
+}
 
 /// <https://spark.apache.org/docs/latest/api/sql/index.html#factorial>
 #[derive(Debug, PartialEq, Eq, Hash)]
 pub struct SparkFactorial {
     signature: Signature,
     aliases: Vec<String>,
+    precision: FactorialPrecision,
 }
 
 impl Default for SparkFactorial {
@@ -44,10 +72,22 @@ impl Default for SparkFactorial {
 impl SparkFactorial {
     pub fn new() -> Self {
         Self {
-            signature: Signature::exact(vec![Int32], Volatility::Immutable),
+            signature: Signature::uniform(
+                1,
+                vec![Int8, Int16, Int32, Int64],
+                Volatility::Immutable,
+            ),
             aliases: vec![],
+            precision: FactorialPrecision::Int64,
         }
     }
+
+    /// Returns this function configured to widen its result to the given
+    /// [`FactorialPrecision`] instead of Spark's default `i64` behavior.
+    pub fn with_precision(mut self, precision: FactorialPrecision) -> Self {
+        self.precision = precision;
+        self
+    }
 }
 
 impl ScalarUDFImpl for SparkFactorial {
@@ -71,11 +111,16 @@ impl ScalarUDFImpl for SparkFactorial {
 
     fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
         let nullable = args.arg_fields.iter().any(|f| f.is_nullable());
-        Ok(Arc::new(Field::new(self.name(), Int64, nullable)))
+        let data_type = match self.precision {
+            FactorialPrecision::Int64 => Int64,
+            FactorialPrecision::Decimal128 => DataType::Decimal128(DECIMAL128_PRECISION, 0),
+            FactorialPrecision::Decimal256 => DataType::Decimal256(DECIMAL256_PRECISION, 0),
+        };
+        Ok(Arc::new(Field::new(self.name(), data_type, nullable)))
     }
 
     fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
-        spark_factorial(&args.args)
+        spark_factorial(&args.args, self.precision)
     }
 
     fn aliases(&self) -> &[String] {
@@ -107,29 +152,91 @@ const FACTORIALS: [i64; 21] = [
     2432902008176640000,
 ];
 
-pub fn spark_factorial(args: &[ColumnarValue]) -> Result<ColumnarValue, DataFusionError> {
+pub fn spark_factorial(
+    args: &[ColumnarValue],
+    precision: FactorialPrecision,
+) -> Result<ColumnarValue, DataFusionError> {
     let [arg] = take_function_args("factorial", args)?;
 
     match arg {
-        ColumnarValue::Scalar(ScalarValue::Int32(value)) => {
-            let result = compute_factorial(*value);
-            Ok(ColumnarValue::Scalar(ScalarValue::Int64(result)))
+        ColumnarValue::Scalar(scalar) => {
+            let value = scalar_to_i32(scalar)?;
+            let result = match precision {
+                FactorialPrecision::Int64 => ScalarValue::Int64(compute_factorial(value)),
+                FactorialPrecision::Decimal128 => ScalarValue::Decimal128(
+                    compute_factorial_decimal128(value),
+                    DECIMAL128_PRECISION,
+                    0,
+                ),
+                FactorialPrecision::Decimal256 => ScalarValue::Decimal256(
+                    compute_factorial_decimal256(value),
+                    DECIMAL256_PRECISION,
+                    0,
+                ),
+            };
+            Ok(ColumnarValue::Scalar(result))
         }
-        ColumnarValue::Scalar(other) => {
-            exec_err!("`factorial` got an unexpected scalar type: {}", other)
+        ColumnarValue::Array(array) => {
+            let values = array_to_i32_values(array)?;
+            match precision {
+                FactorialPrecision::Int64 => {
+                    let result: Int64Array =
+                        values.iter().map(|v| compute_factorial(*v)).collect();
+                    Ok(ColumnarValue::Array(Arc::new(result)))
+                }
+                FactorialPrecision::Decimal128 => {
+                    let result: Decimal128Array = values
+                        .iter()
+                        .map(|v| compute_factorial_decimal128(*v))
+                        .collect::<Decimal128Array>()
+                        .with_precision_and_scale(DECIMAL128_PRECISION, 0)?;
+                    Ok(ColumnarValue::Array(Arc::new(result)))
+                }
+                FactorialPrecision::Decimal256 => {
+                    let result: Decimal256Array = values
+                        .iter()
+                        .map(|v| compute_factorial_decimal256(*v))
+                        .collect::<Decimal256Array>()
+                        .with_precision_and_scale(DECIMAL256_PRECISION, 0)?;
+                    Ok(ColumnarValue::Array(Arc::new(result)))
+                }
+            }
         }
-        ColumnarValue::Array(array) => match array.data_type() {
-            Int32 => {
-                let array = as_int32_array(array)?;
+    }
+}
 
-                let result: Int64Array = array.iter().map(compute_factorial).collect();
+/// Downcasts an integer scalar of any width accepted by [`SparkFactorial`]'s
+/// signature to an `i32`-range value, returning `None` (to be treated as
+/// NULL by [`compute_factorial`]) if it doesn't fit.
+fn scalar_to_i32(scalar: &ScalarValue) -> Result<Option<i32>> {
+    match scalar {
+        ScalarValue::Int8(value) => Ok(value.map(i32::from)),
+        ScalarValue::Int16(value) => Ok(value.map(i32::from)),
+        ScalarValue::Int32(value) => Ok(*value),
+        ScalarValue::Int64(value) => Ok(value.and_then(|v| i32::try_from(v).ok())),
+        other => exec_err!("`factorial` got an unexpected scalar type: {other}"),
+    }
+}
 
-                Ok(ColumnarValue::Array(Arc::new(result)))
-            }
-            other => {
-                exec_err!("`factorial` got an unexpected argument type: {}", other)
-            }
-        },
+/// Downcasts an integer array of any width accepted by [`SparkFactorial`]'s
+/// signature to `i32`-range values, the same way [`scalar_to_i32`] does for
+/// scalars.
+fn array_to_i32_values(array: &dyn Array) -> Result<Vec<Option<i32>>> {
+    match array.data_type() {
+        Int8 => Ok(as_int8_array(array)?
+            .iter()
+            .map(|v| v.map(i32::from))
+            .collect()),
+        Int16 => Ok(as_int16_array(array)?
+            .iter()
+            .map(|v| v.map(i32::from))
+            .collect()),
+        Int32 => Ok(as_int32_array(array)?.iter().collect()),
+        Int64 => Ok(as_int64_array(array)?
+            .iter()
+            .map(|v| v.and_then(|v| i32::try_from(v).ok()))
+            .collect()),
+        other => exec_err!("`factorial` got an unexpected argument type: {other}"),
     }
 }
 
@@ -139,10 +246,40 @@ fn compute_factorial(num: Option<i32>) -> Option<i64> {
         .map(|v| FACTORIALS[v as usize])
 }
 
+/// Computes `num!` as an `i128`, returning `None` once the result no longer
+/// fits within [`DECIMAL128_PRECISION`] digits (or `num` is negative).
+fn compute_factorial_decimal128(num: Option<i32>) -> Option<i128> {
+    let num = num.filter(|&v| v >= 0)?;
+    let mut acc: i128 = 1;
+    for i in 2..=i128::from(num) {
+        acc = acc.checked_mul(i)?;
+        if acc.to_string().len() > DECIMAL128_PRECISION as usize {
+            return None;
+        }
+    }
+    Some(acc)
+}
+
+/// Computes `num!` as an [`i256`], returning `None` once the result no
+/// longer fits within [`DECIMAL256_PRECISION`] digits (or `num` is
+/// negative).
+fn compute_factorial_decimal256(num: Option<i32>) -> Option<i256> {
+    let num = num.filter(|&v| v >= 0)?;
+    let mut acc = i256::from_i128(1);
+    for i in 2..=num {
+        acc = acc.checked_mul(i256::from_i128(i128::from(i)))?;
+        if acc.to_string().len() > DECIMAL256_PRECISION as usize {
+            return None;
+        }
+    }
+    Some(acc)
+}
+
 #[cfg(test)]
 mod test {
-    use crate::function::math::factorial::spark_factorial;
-    use arrow::array::{Int32Array, Int64Array};
+    use crate::function::math::factorial::{FactorialPrecision, spark_factorial};
+    use arrow::array::{Int16Array, Int32Array, Int64Array};
+    use arrow::datatypes::i256;
     use datafusion_common::ScalarValue;
     use datafusion_common::cast::as_int64_array;
     use datafusion_expr::ColumnarValue;
@@ -162,7 +299,7 @@ mod test {
         ]);
 
         let args = ColumnarValue::Array(Arc::new(input));
-        let result = spark_factorial(&[args]).unwrap();
+        let result = spark_factorial(&[args], FactorialPrecision::Int64).unwrap();
         let result = match result {
             ColumnarValue::Array(array) => array,
             _ => panic!("Expected array"),
@@ -188,7 +325,7 @@ mod test {
         let input = ScalarValue::Int32(Some(5));
 
         let args = ColumnarValue::Scalar(input);
-        let result = spark_factorial(&[args]).unwrap();
+        let result = spark_factorial(&[args], FactorialPrecision::Int64).unwrap();
         let result = match result {
             ColumnarValue::Scalar(ScalarValue::Int64(val)) => val,
             _ => panic!("Expected scalar"),
@@ -202,13 +339,13 @@ mod test {
     #[test]
     fn test_factorial_nullability() {
         use arrow::array::{Int32Array, Int64Array};
-        use datafusion_common::cast::as_int64_array;
         use datafusion_common::ScalarValue;
+        use datafusion_common::cast::as_int64_array;
         use datafusion_expr::ColumnarValue;
         use std::sync::Arc;
 
         let scalar_null = ColumnarValue::Scalar(ScalarValue::Int32(None));
-        let result = spark_factorial(&[scalar_null]).unwrap();
+        let result = spark_factorial(&[scalar_null], FactorialPrecision::Int64).unwrap();
 
         match result {
             ColumnarValue::Scalar(ScalarValue::Int64(val)) => {
@@ -219,7 +356,7 @@ mod test {
 
         let input = Int32Array::from(vec![None, None, None]);
         let args = ColumnarValue::Array(Arc::new(input));
-        let result = spark_factorial(&[args]).unwrap();
+        let result = spark_factorial(&[args], FactorialPrecision::Int64).unwrap();
 
         let array = match result {
             ColumnarValue::Array(array) => array,
@@ -232,5 +369,112 @@ mod test {
         assert_eq!(actual, &expected);
     }
 
+    #[test]
+    fn test_spark_factorial_int16() {
+        let input = Int16Array::from(vec![Some(5), Some(0), None]);
 
+        let args = ColumnarValue::Array(Arc::new(input));
+        let result = spark_factorial(&[args], FactorialPrecision::Int64).unwrap();
+        let result = match result {
+            ColumnarValue::Array(array) => array,
+            _ => panic!("Expected array"),
+        };
+
+        let actual = as_int64_array(&result).unwrap();
+        let expected = Int64Array::from(vec![Some(120), Some(1), None]);
+
+        assert_eq!(actual, &expected);
+    }
+
+    #[test]
+    fn test_spark_factorial_int64() {
+        let input = Int64Array::from(vec![Some(5), Some(20), Some(21), None]);
+
+        let args = ColumnarValue::Array(Arc::new(input));
+        let result = spark_factorial(&[args], FactorialPrecision::Int64).unwrap();
+        let result = match result {
+            ColumnarValue::Array(array) => array,
+            _ => panic!("Expected array"),
+        };
+
+        let actual = as_int64_array(&result).unwrap();
+        let expected = Int64Array::from(vec![Some(120), Some(2432902008176640000), None, None]);
+
+        assert_eq!(actual, &expected);
+    }
+
+    #[test]
+    fn test_spark_factorial_int64_out_of_i32_range() {
+        let input = ScalarValue::Int64(Some(i64::from(i32::MAX) + 1));
+
+        let args = ColumnarValue::Scalar(input);
+        let result = spark_factorial(&[args], FactorialPrecision::Int64).unwrap();
+        let result = match result {
+            ColumnarValue::Scalar(ScalarValue::Int64(val)) => val,
+            _ => panic!("Expected scalar"),
+        };
+
+        assert_eq!(result, None, "Expected NULL for a value outside i32 range");
+    }
+
+    #[test]
+    fn test_spark_factorial_decimal128() {
+        let input = ScalarValue::Int32(Some(33));
+
+        let args = ColumnarValue::Scalar(input);
+        let result = spark_factorial(&[args], FactorialPrecision::Decimal128).unwrap();
+        let result = match result {
+            ColumnarValue::Scalar(ScalarValue::Decimal128(val, 38, 0)) => val,
+            _ => panic!("Expected Decimal128(38, 0) scalar"),
+        };
+
+        let expected = 8_683_317_618_811_886_495_518_194_401_280_000_000_i128;
+        assert_eq!(result, Some(expected));
+    }
+
+    #[test]
+    fn test_spark_factorial_decimal128_overflow() {
+        let input = ScalarValue::Int32(Some(34));
+
+        let args = ColumnarValue::Scalar(input);
+        let result = spark_factorial(&[args], FactorialPrecision::Decimal128).unwrap();
+        let result = match result {
+            ColumnarValue::Scalar(ScalarValue::Decimal128(val, 38, 0)) => val,
+            _ => panic!("Expected Decimal128(38, 0) scalar"),
+        };
+
+        assert_eq!(result, None, "34! exceeds Decimal128(38, 0) and should be NULL");
+    }
+
+    #[test]
+    fn test_spark_factorial_decimal256() {
+        let input = ScalarValue::Int32(Some(56));
+
+        let args = ColumnarValue::Scalar(input);
+        let result = spark_factorial(&[args], FactorialPrecision::Decimal256).unwrap();
+        let result = match result {
+            ColumnarValue::Scalar(ScalarValue::Decimal256(val, 76, 0)) => val,
+            _ => panic!("Expected Decimal256(76, 0) scalar"),
+        };
+
+        let expected = i256::from_string(
+            "710998587804863451854045647463724949736497978881168458687447040000000000000",
+        )
+        .unwrap();
+        assert_eq!(result, Some(expected));
+    }
+
+    #[test]
+    fn test_spark_factorial_decimal256_overflow() {
+        let input = ScalarValue::Int32(Some(57));
+
+        let args = ColumnarValue::Scalar(input);
+        let result = spark_factorial(&[args], FactorialPrecision::Decimal256).unwrap();
+        let result = match result {
+            ColumnarValue::Scalar(ScalarValue::Decimal256(val, 76, 0)) => val,
+            _ => panic!("Expected Decimal256(76, 0) scalar"),
+        };
+
+        assert_eq!(result, None, "57! exceeds Decimal256(76, 0) and should be NULL");
+    }
 }